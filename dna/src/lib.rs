@@ -4,6 +4,8 @@
 
 use std::{convert::TryFrom, fmt::Display, str::FromStr};
 
+pub mod parser;
+
 /// A nucleotide
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Nuc {
@@ -75,33 +77,471 @@ impl TryFrom<u8> for Nuc {
     }
 }
 
+impl Nuc {
+    /// Returns the complementary base: A pairs with T, and C pairs with G.
+    pub fn complement(self) -> Self {
+        match self {
+            Self::A => Self::T,
+            Self::T => Self::A,
+            Self::C => Self::G,
+            Self::G => Self::C,
+        }
+    }
+}
 
-// TODO: add a packed module with the PackedDna struct
-//
-// this struct must have the following:
-// 1. A representation that is more memory efficient that simply storing a vector of `Nuc`
-// 2. A FromStr implementation (should be case insensitive like the `Nuc` impl)
-// 3. A `FromIterator` implementation to construct it from an iterator over `Nuc`s
-// 4. A `fn get(&self, idx: usize) -> Nuc` getter for a particular nucleotide
-//
-// Make sure to unit test and document all elements
-// Also, the internal representation of the PackedDna struct should be privately scoped
+/// A ribonucleotide, as transcribed from DNA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RnaNuc {
+    /// Adenine
+    A,
+    /// Cytosine
+    C,
+    /// Guanine
+    G,
+    /// Uracil
+    U,
+}
 
-/// A module containing a more memory-efficient representation for DNA.
+impl TryFrom<char> for RnaNuc {
+    type Error = ParseNucError<char>;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value.to_ascii_uppercase() {
+            'A' => Ok(Self::A),
+            'C' => Ok(Self::C),
+            'G' => Ok(Self::G),
+            'U' => Ok(Self::U),
+            _ => Err(ParseNucError(value)),
+        }
+    }
+}
+
+impl FromStr for RnaNuc {
+    type Err = ParseNucError<String>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let upper = s.to_ascii_uppercase();
+        match upper.as_str() {
+            "A" => Ok(Self::A),
+            "C" => Ok(Self::C),
+            "G" => Ok(Self::G),
+            "U" => Ok(Self::U),
+            _ => Err(ParseNucError(upper)),
+        }
+    }
+}
+
+impl TryFrom<u8> for RnaNuc {
+    type Error = ParseNucError<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::A),
+            1 => Ok(Self::C),
+            2 => Ok(Self::G),
+            3 => Ok(Self::U),
+            _ => Err(ParseNucError(value)),
+        }
+    }
+}
+
+/// A nucleotide drawn from the full IUPAC ambiguity code alphabet, used for
+/// sequencer output and reference genomes where a position may not resolve
+/// to a single base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmbiguousNuc {
+    /// Adenine
+    A,
+    /// Cytosine
+    C,
+    /// Guanine
+    G,
+    /// Thymine
+    T,
+    /// A or G (puRine)
+    R,
+    /// C or T (pYrimidine)
+    Y,
+    /// C or G (Strong)
+    S,
+    /// A or T (Weak)
+    W,
+    /// G or T (Keto)
+    K,
+    /// A or C (aMino)
+    M,
+    /// C, G, or T (not A)
+    B,
+    /// A, G, or T (not C)
+    D,
+    /// A, C, or T (not G)
+    H,
+    /// A, C, or G (not T)
+    V,
+    /// Any base
+    N,
+}
+
+impl AmbiguousNuc {
+    /// Returns a 4-bit mask of the bases this code can represent, with bit 0
+    /// set for A, bit 1 for C, bit 2 for G, and bit 3 for T.
+    pub fn bitmask(self) -> u8 {
+        const A: u8 = 0b0001;
+        const C: u8 = 0b0010;
+        const G: u8 = 0b0100;
+        const T: u8 = 0b1000;
+        match self {
+            Self::A => A,
+            Self::C => C,
+            Self::G => G,
+            Self::T => T,
+            Self::R => A | G,
+            Self::Y => C | T,
+            Self::S => C | G,
+            Self::W => A | T,
+            Self::K => G | T,
+            Self::M => A | C,
+            Self::B => C | G | T,
+            Self::D => A | G | T,
+            Self::H => A | C | T,
+            Self::V => A | C | G,
+            Self::N => A | C | G | T,
+        }
+    }
+
+    /// Returns the candidate `Nuc` bases this code can represent.
+    pub fn bases(self) -> &'static [Nuc] {
+        match self.bitmask() {
+            0b0001 => &[Nuc::A],
+            0b0010 => &[Nuc::C],
+            0b0100 => &[Nuc::G],
+            0b1000 => &[Nuc::T],
+            0b0101 => &[Nuc::A, Nuc::G],
+            0b1010 => &[Nuc::C, Nuc::T],
+            0b0110 => &[Nuc::C, Nuc::G],
+            0b1001 => &[Nuc::A, Nuc::T],
+            0b1100 => &[Nuc::G, Nuc::T],
+            0b0011 => &[Nuc::A, Nuc::C],
+            0b1110 => &[Nuc::C, Nuc::G, Nuc::T],
+            0b1101 => &[Nuc::A, Nuc::G, Nuc::T],
+            0b1011 => &[Nuc::A, Nuc::C, Nuc::T],
+            0b0111 => &[Nuc::A, Nuc::C, Nuc::G],
+            0b1111 => &[Nuc::A, Nuc::C, Nuc::G, Nuc::T],
+            mask => unreachable!("invalid IUPAC bitmask: {:#06b}", mask),
+        }
+    }
+}
+
+impl TryFrom<char> for AmbiguousNuc {
+    type Error = ParseNucError<char>;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value.to_ascii_uppercase() {
+            'A' => Ok(Self::A),
+            'C' => Ok(Self::C),
+            'G' => Ok(Self::G),
+            'T' => Ok(Self::T),
+            'R' => Ok(Self::R),
+            'Y' => Ok(Self::Y),
+            'S' => Ok(Self::S),
+            'W' => Ok(Self::W),
+            'K' => Ok(Self::K),
+            'M' => Ok(Self::M),
+            'B' => Ok(Self::B),
+            'D' => Ok(Self::D),
+            'H' => Ok(Self::H),
+            'V' => Ok(Self::V),
+            'N' => Ok(Self::N),
+            _ => Err(ParseNucError(value)),
+        }
+    }
+}
+
+impl FromStr for AmbiguousNuc {
+    type Err = ParseNucError<String>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let upper = s.to_ascii_uppercase();
+        let mut chars = upper.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Self::try_from(c).map_err(|_| ParseNucError(upper)),
+            _ => Err(ParseNucError(upper)),
+        }
+    }
+}
 
-mod packed {
+/// A module containing a more memory-efficient representation for DNA.
+///
+/// Nucleotides are packed four to a byte using their 2-bit codes (see
+/// `Nuc::try_from(u8)`), so a `PackedDna` of `n` bases uses roughly `n / 4`
+/// bytes instead of `n` bytes (or `n` enum values).
+pub mod packed {
     use std::convert::TryFrom;
     use std::str::FromStr;
-    use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
-    #[derive(Debug, PartialEq)]
+    /// A memory-efficient representation for DNA that packs four nucleotides
+    /// per byte using 2-bit codes.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct PackedDna {
+        /// The packed nucleotide codes, four per byte. Bits `[0..2)` of
+        /// `bytes[i]` hold nucleotide `4 * i`, `[2..4)` hold `4 * i + 1`,
+        /// and so on. Any unused bits in the final byte are padding.
+        bytes: Vec<u8>,
+        /// The number of real nucleotides stored, which may be fewer than
+        /// `bytes.len() * 4` if the last byte is only partially filled.
+        len: usize,
+    }
+
+    impl PackedDna {
+        /// Creates an empty `PackedDna` sequence.
+        pub fn new() -> Self {
+            Self {
+                bytes: Vec::new(),
+                len: 0,
+            }
+        }
+
+        /// Returns the number of nucleotides in the sequence.
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// Returns `true` if the sequence contains no nucleotides.
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// Retrieves the nucleotide at the specified index.
+        ///
+        /// # Arguments
+        ///
+        /// * `idx` - The index of the nucleotide to retrieve.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the index is out of bounds.
+        ///
+        /// # Returns
+        ///
+        /// The nucleotide at the specified index.
+        pub fn get(&self, idx: usize) -> crate::Nuc {
+            assert!(
+                idx < self.len,
+                "index {} out of bounds for PackedDna of length {}",
+                idx,
+                self.len
+            );
+            let byte = idx / 4;
+            let shift = (idx % 4) * 2;
+            let code = (self.bytes[byte] >> shift) & 0b11;
+            crate::Nuc::try_from(code).unwrap()
+        }
+
+        /// Returns an iterator over the nucleotides in the sequence, in order.
+        pub fn iter(&self) -> impl DoubleEndedIterator<Item = crate::Nuc> + '_ {
+            (0..self.len).map(move |idx| self.get(idx))
+        }
+
+        /// Appends a single nucleotide to the end of the sequence, packing it
+        /// into the current (or a freshly allocated) trailing byte.
+        fn push(&mut self, nuc: crate::Nuc) {
+            let byte = self.len / 4;
+            let shift = (self.len % 4) * 2;
+            if byte == self.bytes.len() {
+                self.bytes.push(0);
+            }
+            self.bytes[byte] |= (nuc as u8) << shift;
+            self.len += 1;
+        }
+
+        /// Returns the reverse complement of this sequence: the bases in
+        /// reverse order, each replaced by its complementary base.
+        pub fn reverse_complement(&self) -> PackedDna {
+            self.iter().rev().map(crate::Nuc::complement).collect()
+        }
+
+        /// Transcribes this DNA sequence into RNA, replacing each base with
+        /// its RNA complement (G->C, C->G, T->A, A->U).
+        ///
+        /// Every valid DNA sequence has a well-defined transcription, so this
+        /// returns the `PackedRna` directly rather than a `Result`.
+        pub fn transcribe(&self) -> PackedRna {
+            self.iter()
+                .map(|nuc| match nuc {
+                    crate::Nuc::G => crate::RnaNuc::C,
+                    crate::Nuc::C => crate::RnaNuc::G,
+                    crate::Nuc::T => crate::RnaNuc::A,
+                    crate::Nuc::A => crate::RnaNuc::U,
+                })
+                .collect()
+        }
+
+        /// Returns an iterator over the `k`-mers of this sequence, each
+        /// encoded as the `2 * k` low bits of a `u64` using the same 2-bit
+        /// codes as the packed representation (the most recent base in the
+        /// window occupies the lowest bits).
+        ///
+        /// # Panics
+        ///
+        /// Panics if `k` is zero or greater than 32, since a k-mer must fit
+        /// in 64 bits at 2 bits per base.
+        pub fn kmers(&self, k: usize) -> impl Iterator<Item = u64> + '_ {
+            assert!(k > 0 && k <= 32, "k must be between 1 and 32, got {}", k);
+            let mask = if k == 32 { u64::MAX } else { (1u64 << (2 * k)) - 1 };
+            let mut code: u64 = 0;
+            (0..self.len).filter_map(move |idx| {
+                code = ((code << 2) | self.get(idx) as u64) & mask;
+                if idx + 1 >= k {
+                    Some(code)
+                } else {
+                    None
+                }
+            })
+        }
+
+        /// Counts the canonical `k`-mers of this sequence: a k-mer and its
+        /// reverse complement are counted together under whichever encoding
+        /// is numerically smaller, via `canonical_kmer`.
+        pub fn count_kmers(&self, k: usize) -> std::collections::HashMap<u64, usize> {
+            let mut counts = std::collections::HashMap::new();
+            for kmer in self.kmers(k) {
+                *counts.entry(canonical_kmer(kmer, k)).or_insert(0) += 1;
+            }
+            counts
+        }
+
+        /// Encodes this sequence as a compact, self-describing byte buffer: a
+        /// LEB128 varint holding the nucleotide count, followed by the raw
+        /// packed 2-bit bytes. Decode with [`PackedDna::from_bytes`].
+        pub fn to_bytes(&self) -> Vec<u8> {
+            let mut out = encode_varint(self.len as u64);
+            out.extend_from_slice(&self.bytes);
+            out
+        }
+
+        /// Decodes a buffer produced by [`PackedDna::to_bytes`].
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the varint header is truncated, if the
+        /// number of packed bytes doesn't match the declared length, or if
+        /// the unused high bits of the final byte are not zero.
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+            let (len, header_len) = decode_varint(bytes)?;
+            let len = len as usize;
+            let data = &bytes[header_len..];
+
+            let expected_bytes = len.div_ceil(4);
+            if data.len() != expected_bytes {
+                return Err(DecodeError::LengthMismatch {
+                    expected: expected_bytes,
+                    actual: data.len(),
+                });
+            }
+
+            let remainder = len % 4;
+            if remainder != 0 {
+                let used_bits = remainder * 2;
+                let padding_mask = !0u8 << used_bits;
+                if data[expected_bytes - 1] & padding_mask != 0 {
+                    return Err(DecodeError::NonZeroPadding);
+                }
+            }
+
+            Ok(Self {
+                bytes: data.to_vec(),
+                len,
+            })
+        }
+    }
+
+    /// An error that can occur while decoding a [`PackedDna`] from bytes.
+    #[derive(Debug, thiserror::Error)]
+    pub enum DecodeError {
+        /// The buffer ended before a complete LEB128 varint header could be read.
+        #[error("truncated varint header")]
+        TruncatedHeader,
+        /// The number of packed bytes didn't match what the length header implies.
+        #[error("expected {expected} packed bytes for the declared length, found {actual}")]
+        LengthMismatch {
+            /// The number of packed bytes the length header implies.
+            expected: usize,
+            /// The number of packed bytes actually present.
+            actual: usize,
+        },
+        /// The unused high bits of the final packed byte were not zero.
+        #[error("unused padding bits in the final byte were not zero")]
+        NonZeroPadding,
+        /// The varint header encoded a value that doesn't fit in a `u64`.
+        #[error("varint header overflows a u64")]
+        HeaderOverflow,
+    }
 
-    /// A more memory-efficient representation for DNA.
-    pub struct PackedDna(pub Vec<u8>);
+    /// Encodes a `u64` as a LEB128 varint: 7 bits of value per byte, with the
+    /// high bit set on every byte but the last.
+    fn encode_varint(mut value: u64) -> Vec<u8> {
+        let mut out = Vec::new();
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                out.push(byte);
+                break;
+            }
+            out.push(byte | 0x80);
+        }
+        out
+    }
 
+    /// Decodes a LEB128 varint from the start of `input`, returning the value
+    /// and the number of bytes consumed.
+    fn decode_varint(input: &[u8]) -> Result<(u64, usize), DecodeError> {
+        let mut value: u64 = 0;
+        for (i, &byte) in input.iter().enumerate() {
+            // A u64 holds at most 10 groups of 7 bits, and the 10th group
+            // only has room for its lowest bit; reject anything beyond that
+            // instead of overflowing the shift.
+            if i >= 10 || (i == 9 && byte & 0x7f > 1) {
+                return Err(DecodeError::HeaderOverflow);
+            }
+            value |= ((byte & 0x7f) as u64) << (7 * i);
+            if byte & 0x80 == 0 {
+                return Ok((value, i + 1));
+            }
+        }
+        Err(DecodeError::TruncatedHeader)
+    }
+
+    /// Returns the reverse complement of a 2-bit-encoded k-mer of length `k`,
+    /// reversing the base order and complementing each 2-bit code (A<->T is
+    /// 0<->3, C<->G is 1<->2, so complementing a code is `3 - code`).
+    fn reverse_complement_kmer(kmer: u64, k: usize) -> u64 {
+        let mut kmer = kmer;
+        let mut rc = 0u64;
+        for _ in 0..k {
+            let code = kmer & 0b11;
+            kmer >>= 2;
+            rc = (rc << 2) | (3 - code);
+        }
+        rc
+    }
+
+    /// Returns the canonical encoding of a `k`-mer: the smaller of its 2-bit
+    /// encoding and the encoding of its reverse complement, so that a k-mer
+    /// and its reverse complement are treated as the same k-mer.
+    pub fn canonical_kmer(kmer: u64, k: usize) -> u64 {
+        kmer.min(reverse_complement_kmer(kmer, k))
+    }
+
+    impl Default for PackedDna {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
 
     impl FromStr for PackedDna {
         type Err = crate::ParseNucError<char>;
+
         /// Converts a string slice to a `PackedDna` instance.
         ///
         /// # Arguments
@@ -113,17 +553,14 @@ mod packed {
         /// A `Result` containing the `PackedDna` instance if the parsing is successful,
         /// or a `ParseNucError` if an error occurs.
         fn from_str(s: &str) -> Result<Self, Self::Err> {
-            let nucleotides: Result<Vec<crate::Nuc>, _> = s.chars().map(crate::Nuc::try_from).collect();
-            nucleotides.map(|nucs: Vec<crate::Nuc>| {
-                let packed_nucs: Vec<u8> = nucs.into_iter().map(|n: crate::Nuc| n as u8).collect();
-                PackedDna(packed_nucs)
-            })
+            let mut packed = PackedDna::new();
+            for c in s.chars() {
+                packed.push(crate::Nuc::try_from(c)?);
+            }
+            Ok(packed)
         }
     }
 
-    
-    
-    
     impl std::iter::FromIterator<crate::Nuc> for PackedDna {
         /// Constructs a `PackedDna` instance from an iterator over `Nuc` values.
         ///
@@ -135,28 +572,233 @@ mod packed {
         ///
         /// The constructed `PackedDna` instance.
         fn from_iter<I: IntoIterator<Item = crate::Nuc>>(iter: I) -> Self {
-            let nucleotides: Vec<u8> = iter.into_iter().map(|n| n as u8).collect();
-            PackedDna(nucleotides)
+            let mut packed = PackedDna::new();
+            for nuc in iter {
+                packed.push(nuc);
+            }
+            packed
         }
     }
 
-    impl PackedDna {
-        /// Retrieves the nucleotide at the specified index.
+    /// A memory-efficient representation for RNA, packed the same way as
+    /// `PackedDna`: four ribonucleotides per byte using 2-bit codes.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct PackedRna {
+        /// The packed ribonucleotide codes; see `PackedDna::bytes`.
+        bytes: Vec<u8>,
+        /// The number of real ribonucleotides stored.
+        len: usize,
+    }
+
+    impl PackedRna {
+        /// Creates an empty `PackedRna` sequence.
+        pub fn new() -> Self {
+            Self {
+                bytes: Vec::new(),
+                len: 0,
+            }
+        }
+
+        /// Returns the number of ribonucleotides in the sequence.
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// Returns `true` if the sequence contains no ribonucleotides.
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// Retrieves the ribonucleotide at the specified index.
         ///
-        /// # Arguments
+        /// # Panics
         ///
-        /// * `idx` - The index of the nucleotide to retrieve.
+        /// Panics if the index is out of bounds.
+        pub fn get(&self, idx: usize) -> crate::RnaNuc {
+            assert!(
+                idx < self.len,
+                "index {} out of bounds for PackedRna of length {}",
+                idx,
+                self.len
+            );
+            let byte = idx / 4;
+            let shift = (idx % 4) * 2;
+            let code = (self.bytes[byte] >> shift) & 0b11;
+            crate::RnaNuc::try_from(code).unwrap()
+        }
+
+        /// Returns an iterator over the ribonucleotides in the sequence, in order.
+        pub fn iter(&self) -> impl DoubleEndedIterator<Item = crate::RnaNuc> + '_ {
+            (0..self.len).map(move |idx| self.get(idx))
+        }
+
+        /// Appends a single ribonucleotide to the end of the sequence.
+        fn push(&mut self, nuc: crate::RnaNuc) {
+            let byte = self.len / 4;
+            let shift = (self.len % 4) * 2;
+            if byte == self.bytes.len() {
+                self.bytes.push(0);
+            }
+            self.bytes[byte] |= (nuc as u8) << shift;
+            self.len += 1;
+        }
+    }
+
+    impl Default for PackedRna {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl FromStr for PackedRna {
+        type Err = crate::ParseNucError<char>;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let mut packed = PackedRna::new();
+            for c in s.chars() {
+                packed.push(crate::RnaNuc::try_from(c)?);
+            }
+            Ok(packed)
+        }
+    }
+
+    impl std::iter::FromIterator<crate::RnaNuc> for PackedRna {
+        fn from_iter<I: IntoIterator<Item = crate::RnaNuc>>(iter: I) -> Self {
+            let mut packed = PackedRna::new();
+            for nuc in iter {
+                packed.push(nuc);
+            }
+            packed
+        }
+    }
+
+    /// A representation for DNA that may contain IUPAC ambiguity codes.
+    ///
+    /// Ambiguity codes don't fit the 2-bit packing `PackedDna` uses, so each
+    /// base is instead stored as a 4-bit mask (two per byte) of the
+    /// nucleotides it may represent; see `crate::AmbiguousNuc::bitmask`.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct PackedAmbiguousDna {
+        /// The packed ambiguity masks, two per byte: bits `[0..4)` of
+        /// `nibbles[i]` hold base `2 * i`, bits `[4..8)` hold `2 * i + 1`.
+        nibbles: Vec<u8>,
+        /// The number of real bases stored.
+        len: usize,
+    }
+
+    impl PackedAmbiguousDna {
+        /// Creates an empty `PackedAmbiguousDna` sequence.
+        pub fn new() -> Self {
+            Self {
+                nibbles: Vec::new(),
+                len: 0,
+            }
+        }
+
+        /// Returns the number of bases in the sequence.
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// Returns `true` if the sequence contains no bases.
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// Retrieves the ambiguity code at the specified index.
         ///
         /// # Panics
         ///
         /// Panics if the index is out of bounds.
+        pub fn get(&self, idx: usize) -> crate::AmbiguousNuc {
+            assert!(
+                idx < self.len,
+                "index {} out of bounds for PackedAmbiguousDna of length {}",
+                idx,
+                self.len
+            );
+            let byte = idx / 2;
+            let shift = (idx % 2) * 4;
+            let mask = (self.nibbles[byte] >> shift) & 0b1111;
+            ambiguous_nuc_from_bitmask(mask)
+        }
+
+        /// Returns the candidate `Nuc` bases the ambiguity code at `idx` may
+        /// represent.
         ///
-        /// # Returns
+        /// # Panics
         ///
-        /// The nucleotide at the specified index.
-        pub fn get(&self, idx: usize) -> crate::Nuc {
-            let value = self.0[idx];
-            crate::Nuc::try_from(value).unwrap()
+        /// Panics if the index is out of bounds.
+        pub fn expand(&self, idx: usize) -> &'static [crate::Nuc] {
+            self.get(idx).bases()
+        }
+
+        /// Returns an iterator over the ambiguity codes in the sequence, in order.
+        pub fn iter(&self) -> impl DoubleEndedIterator<Item = crate::AmbiguousNuc> + '_ {
+            (0..self.len).map(move |idx| self.get(idx))
+        }
+
+        /// Appends a single ambiguity code to the end of the sequence.
+        fn push(&mut self, nuc: crate::AmbiguousNuc) {
+            let byte = self.len / 2;
+            let shift = (self.len % 2) * 4;
+            if byte == self.nibbles.len() {
+                self.nibbles.push(0);
+            }
+            self.nibbles[byte] |= nuc.bitmask() << shift;
+            self.len += 1;
+        }
+    }
+
+    impl Default for PackedAmbiguousDna {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl FromStr for PackedAmbiguousDna {
+        type Err = crate::ParseNucError<char>;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let mut packed = PackedAmbiguousDna::new();
+            for c in s.chars() {
+                packed.push(crate::AmbiguousNuc::try_from(c)?);
+            }
+            Ok(packed)
+        }
+    }
+
+    impl std::iter::FromIterator<crate::AmbiguousNuc> for PackedAmbiguousDna {
+        fn from_iter<I: IntoIterator<Item = crate::AmbiguousNuc>>(iter: I) -> Self {
+            let mut packed = PackedAmbiguousDna::new();
+            for nuc in iter {
+                packed.push(nuc);
+            }
+            packed
+        }
+    }
+
+    /// Recovers the `AmbiguousNuc` variant for a given 4-bit mask, the
+    /// inverse of `AmbiguousNuc::bitmask`.
+    fn ambiguous_nuc_from_bitmask(mask: u8) -> crate::AmbiguousNuc {
+        use crate::AmbiguousNuc::*;
+        match mask {
+            0b0001 => A,
+            0b0010 => C,
+            0b0100 => G,
+            0b1000 => T,
+            0b0101 => R,
+            0b1010 => Y,
+            0b0110 => S,
+            0b1001 => W,
+            0b1100 => K,
+            0b0011 => M,
+            0b1110 => B,
+            0b1101 => D,
+            0b1011 => H,
+            0b0111 => V,
+            0b1111 => N,
+            _ => unreachable!("invalid IUPAC bitmask: {:#06b}", mask),
         }
     }
 }
@@ -169,26 +811,26 @@ mod packed {
 
 #[cfg(test)]
 mod tests {
-    // TODO: fill in tests
     use super::*;
 
     /// Tests the `from_str` function of `PackedDna`.
     #[test]
     fn packed_dna_from_str() {
         // Valid input
-        let expected0: packed::PackedDna = packed::PackedDna(vec![0]);
+        let expected0: packed::PackedDna = vec![Nuc::A].into_iter().collect();
         assert_eq!(packed::PackedDna::from_str("A").unwrap(), expected0);
 
-        let expected1: packed::PackedDna = packed::PackedDna(vec![0, 1, 2, 3]);
+        let expected1: packed::PackedDna = vec![Nuc::A, Nuc::C, Nuc::G, Nuc::T].into_iter().collect();
         assert_eq!(packed::PackedDna::from_str("ACGT").unwrap(), expected1);
         assert_eq!(packed::PackedDna::from_str("acgt").unwrap(), expected1);
         assert_eq!(packed::PackedDna::from_str("ACgt").unwrap(), expected1);
 
-        let expected2: packed::PackedDna = packed::PackedDna(vec![0, 1, 3, 3, 1, 0]);
+        let expected2: packed::PackedDna =
+            vec![Nuc::A, Nuc::C, Nuc::T, Nuc::T, Nuc::C, Nuc::A].into_iter().collect();
         assert_eq!(packed::PackedDna::from_str("ACTTCA").unwrap(), expected2);
-        let expected3: packed::PackedDna = packed::PackedDna(vec![2, 2, 2, 3, 1, 0]);
+        let expected3: packed::PackedDna =
+            vec![Nuc::G, Nuc::G, Nuc::G, Nuc::T, Nuc::C, Nuc::A].into_iter().collect();
         assert_eq!(packed::PackedDna::from_str("gggtca").unwrap(), expected3);
-        
 
         // Invalid input
         assert!(packed::PackedDna::from_str("XYZ").is_err());
@@ -198,18 +840,177 @@ mod tests {
     #[test]
     fn packed_dna_from_iterator() {
         let nucs = vec![Nuc::A, Nuc::C, Nuc::G, Nuc::T];
-        let packed_dna: packed::PackedDna = nucs.into_iter().collect();
-        assert_eq!(packed_dna.0, vec![0, 1, 2, 3]);
+        let packed_dna: packed::PackedDna = nucs.clone().into_iter().collect();
+        assert_eq!(packed_dna.len(), 4);
+        assert_eq!(packed_dna.iter().collect::<Vec<_>>(), nucs);
     }
 
     /// Tests the `get` function of `PackedDna`.
     #[test]
     fn packed_dna_get() {
-        let packed_dna = packed::PackedDna(vec![0, 1, 2, 3]);
+        let packed_dna: packed::PackedDna =
+            vec![Nuc::A, Nuc::C, Nuc::G, Nuc::T].into_iter().collect();
         assert_eq!(packed_dna.get(0), Nuc::A);
         assert_eq!(packed_dna.get(1), Nuc::C);
         assert_eq!(packed_dna.get(2), Nuc::G);
         assert_eq!(packed_dna.get(3), Nuc::T);
     }
+
+    /// Tests that more than four nucleotides spill into a second packed byte.
+    #[test]
+    fn packed_dna_spans_multiple_bytes() {
+        let dna = packed::PackedDna::from_str("ACGTACGTAC").unwrap();
+        assert_eq!(dna.len(), 10);
+        let expected = "ACGTACGTAC"
+            .chars()
+            .map(|c| Nuc::try_from(c).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(dna.iter().collect::<Vec<_>>(), expected);
+    }
+
+    /// Tests that indexing past the end of the sequence panics rather than
+    /// reading into padding bits.
+    #[test]
+    #[should_panic]
+    fn packed_dna_get_out_of_bounds() {
+        let dna = packed::PackedDna::from_str("AC").unwrap();
+        dna.get(2);
+    }
+
+    /// Tests `Nuc::complement`.
+    #[test]
+    fn nuc_complement() {
+        assert_eq!(Nuc::A.complement(), Nuc::T);
+        assert_eq!(Nuc::T.complement(), Nuc::A);
+        assert_eq!(Nuc::C.complement(), Nuc::G);
+        assert_eq!(Nuc::G.complement(), Nuc::C);
+    }
+
+    /// Tests `PackedDna::reverse_complement`.
+    #[test]
+    fn packed_dna_reverse_complement() {
+        let dna = packed::PackedDna::from_str("ACGTT").unwrap();
+        let rc = dna.reverse_complement();
+        assert_eq!(
+            rc.iter().collect::<Vec<_>>(),
+            vec![Nuc::A, Nuc::A, Nuc::C, Nuc::G, Nuc::T]
+        );
+    }
+
+    /// Tests `RnaNuc::try_from(char)` and `FromStr`.
+    #[test]
+    fn rna_nuc_from_char_and_str() {
+        assert_eq!(RnaNuc::try_from('u').unwrap(), RnaNuc::U);
+        assert_eq!(RnaNuc::from_str("g").unwrap(), RnaNuc::G);
+        assert!(RnaNuc::try_from('t').is_err());
+    }
+
+    /// Tests `PackedDna::transcribe`.
+    #[test]
+    fn packed_dna_transcribe() {
+        let dna = packed::PackedDna::from_str("ACGT").unwrap();
+        let rna = dna.transcribe();
+        assert_eq!(
+            rna.iter().collect::<Vec<_>>(),
+            vec![RnaNuc::U, RnaNuc::G, RnaNuc::C, RnaNuc::A]
+        );
+    }
+
+    /// Tests `AmbiguousNuc::try_from(char)` and `bases`.
+    #[test]
+    fn ambiguous_nuc_from_char_and_bases() {
+        assert_eq!(AmbiguousNuc::try_from('n').unwrap(), AmbiguousNuc::N);
+        assert_eq!(AmbiguousNuc::N.bases(), &[Nuc::A, Nuc::C, Nuc::G, Nuc::T]);
+        assert_eq!(AmbiguousNuc::R.bases(), &[Nuc::A, Nuc::G]);
+        assert_eq!(AmbiguousNuc::A.bases(), &[Nuc::A]);
+        assert!(AmbiguousNuc::try_from('x').is_err());
+    }
+
+    /// Tests that `PackedAmbiguousDna` round-trips ambiguity codes through
+    /// its nibble packing.
+    #[test]
+    fn packed_ambiguous_dna_from_str_and_expand() {
+        let dna = packed::PackedAmbiguousDna::from_str("ACGTN").unwrap();
+        assert_eq!(dna.len(), 5);
+        assert_eq!(dna.expand(0), &[Nuc::A]);
+        assert_eq!(dna.expand(3), &[Nuc::T]);
+        assert_eq!(dna.expand(4), &[Nuc::A, Nuc::C, Nuc::G, Nuc::T]);
+
+        assert!(packed::PackedAmbiguousDna::from_str("XYZ").is_err());
+    }
+
+    /// Tests `PackedDna::kmers` slides a window and encodes each with the
+    /// most recent base in the low bits.
+    #[test]
+    fn packed_dna_kmers() {
+        let dna = packed::PackedDna::from_str("ACGT").unwrap();
+        // A=0, C=1, G=2, T=3
+        let kmers: Vec<u64> = dna.kmers(2).collect();
+        assert_eq!(kmers, vec![0b00_01, 0b01_10, 0b10_11]);
+    }
+
+    /// Tests that `packed::canonical_kmer` picks the smaller of a k-mer and
+    /// its reverse complement.
+    #[test]
+    fn canonical_kmer_picks_smaller_strand() {
+        // "AC" (0b0001) reverse-complements to "GT" (0b1011); AC is smaller.
+        let ac = 0b0001;
+        let gt = 0b1011;
+        assert_eq!(packed::canonical_kmer(ac, 2), ac);
+        assert_eq!(packed::canonical_kmer(gt, 2), ac);
+    }
+
+    /// Tests that `PackedDna::count_kmers` merges a k-mer with its reverse
+    /// complement under the canonical encoding.
+    #[test]
+    fn packed_dna_count_kmers_merges_reverse_complement() {
+        let dna = packed::PackedDna::from_str("ACGT").unwrap();
+        let counts = dna.count_kmers(2);
+        // Windows are AC, CG, GT. CG is its own reverse complement, and
+        // AC/GT are reverse complements of each other, so they should merge.
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts.values().sum::<usize>(), 3);
+    }
+
+    /// Tests that `to_bytes`/`from_bytes` round-trip sequences of various
+    /// lengths, including ones that leave padding bits in the final byte.
+    #[test]
+    fn packed_dna_bytes_round_trip() {
+        for s in ["", "A", "ACG", "ACGTACGTAC", "ACGTACGTACGTACGTACGT"] {
+            let dna = packed::PackedDna::from_str(s).unwrap();
+            let bytes = dna.to_bytes();
+            let decoded = packed::PackedDna::from_bytes(&bytes).unwrap();
+            assert_eq!(dna, decoded);
+        }
+    }
+
+    /// Tests that a short varint header is rejected.
+    #[test]
+    fn packed_dna_from_bytes_truncated_header() {
+        assert!(packed::PackedDna::from_bytes(&[0x80]).is_err());
+    }
+
+    /// Tests that a byte count mismatching the declared length is rejected.
+    #[test]
+    fn packed_dna_from_bytes_length_mismatch() {
+        // Declares 4 nucleotides (1 byte) but supplies two bytes.
+        assert!(packed::PackedDna::from_bytes(&[4, 0, 0]).is_err());
+    }
+
+    /// Tests that non-zero padding bits in the final byte are rejected.
+    #[test]
+    fn packed_dna_from_bytes_nonzero_padding() {
+        // Declares 1 nucleotide but sets bits beyond the first 2.
+        assert!(packed::PackedDna::from_bytes(&[1, 0b1111_0000]).is_err());
+    }
+
+    /// Tests that a varint header with more continuation bytes than fit in a
+    /// `u64` is rejected instead of overflowing the decode shift.
+    #[test]
+    fn packed_dna_from_bytes_header_overflow() {
+        let mut bytes = vec![0x80; 10];
+        bytes.push(0x01);
+        assert!(packed::PackedDna::from_bytes(&bytes).is_err());
+    }
 }
 
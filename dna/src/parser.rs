@@ -0,0 +1,253 @@
+//! Parsing for FASTA and FASTQ files, built on the `nom` combinator library.
+//!
+//! Both formats wrap a header/id line around one or more sequence lines; this
+//! module uses `nom`'s byte-level combinators so malformed input is rejected
+//! with a precise error location rather than a generic failure, and then
+//! packs each record's sequence into a `PackedDna`.
+
+use crate::packed::{PackedAmbiguousDna, PackedDna};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while, take_while1},
+    character::complete::line_ending,
+    combinator::eof,
+    multi::many1,
+    IResult,
+};
+use std::str::FromStr;
+
+/// A single parsed record's sequence.
+///
+/// Real sequencer output and reference genomes often contain IUPAC
+/// ambiguity codes (`N`, `R`, `Y`, ...) that don't fit the strict 2-bit
+/// `PackedDna` representation. A record packs into `Dna` when every base is
+/// an unambiguous A/C/G/T, and falls back to the ambiguity-aware
+/// `Ambiguous` representation otherwise.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Record {
+    /// A sequence containing only unambiguous A/C/G/T bases.
+    Dna(PackedDna),
+    /// A sequence containing one or more IUPAC ambiguity codes.
+    Ambiguous(PackedAmbiguousDna),
+}
+
+/// An error that can occur while parsing a FASTA or FASTQ file.
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    /// The input is not well-formed FASTA/FASTQ, or is not valid UTF-8. Holds
+    /// the unparsed remainder of the input, or the `nom` or UTF-8 error
+    /// message when parsing failed outright.
+    #[error("malformed input near: {0:?}")]
+    Malformed(String),
+    /// A record's sequence line contained a character that is not a valid
+    /// nucleotide.
+    #[error(transparent)]
+    InvalidNuc(#[from] crate::ParseNucError<char>),
+}
+
+fn is_header_char(c: char) -> bool {
+    c != '\n' && c != '\r'
+}
+
+fn is_sequence_char(c: char) -> bool {
+    c.is_ascii_alphabetic()
+}
+
+fn end_of_line(input: &str) -> IResult<&str, &str> {
+    alt((line_ending, eof))(input)
+}
+
+fn fasta_header(input: &str) -> IResult<&str, &str> {
+    let (input, _) = tag(">")(input)?;
+    let (input, header) = take_while(is_header_char)(input)?;
+    let (input, _) = end_of_line(input)?;
+    Ok((input, header))
+}
+
+fn fasta_sequence_line(input: &str) -> IResult<&str, &str> {
+    let (input, line) = take_while1(is_sequence_char)(input)?;
+    let (input, _) = end_of_line(input)?;
+    Ok((input, line))
+}
+
+fn fasta_record(input: &str) -> IResult<&str, (String, String)> {
+    let (input, header) = fasta_header(input)?;
+    let (input, lines) = many1(fasta_sequence_line)(input)?;
+    Ok((input, (header.to_string(), lines.concat())))
+}
+
+fn fasta_records(input: &str) -> IResult<&str, Vec<(String, String)>> {
+    many1(fasta_record)(input)
+}
+
+fn fastq_record(input: &str) -> IResult<&str, (String, String)> {
+    let (input, _) = tag("@")(input)?;
+    let (input, id) = take_while(is_header_char)(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, sequence) = take_while1(is_sequence_char)(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, _) = tag("+")(input)?;
+    let (input, _) = take_while(is_header_char)(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, _quality) = take_while1(|c: char| !c.is_whitespace())(input)?;
+    let (input, _) = end_of_line(input)?;
+    Ok((input, (id.to_string(), sequence.to_string())))
+}
+
+fn fastq_records(input: &str) -> IResult<&str, Vec<(String, String)>> {
+    many1(fastq_record)(input)
+}
+
+/// Packs a single record's sequence, falling back to the ambiguity-aware
+/// representation when the sequence contains an IUPAC ambiguity code that
+/// the strict 2-bit `PackedDna` parser rejects.
+fn pack_record(sequence: &str) -> Result<Record, ParseError> {
+    match PackedDna::from_str(sequence) {
+        Ok(dna) => Ok(Record::Dna(dna)),
+        Err(strict_err) => PackedAmbiguousDna::from_str(sequence)
+            .map(Record::Ambiguous)
+            .map_err(|_| ParseError::from(strict_err)),
+    }
+}
+
+fn pack_records(records: Vec<(String, String)>) -> Result<Vec<(String, Record)>, ParseError> {
+    records
+        .into_iter()
+        .map(|(name, sequence)| pack_record(&sequence).map(|record| (name, record)))
+        .collect()
+}
+
+/// Parses a FASTA-formatted string into `(header, Record)` records.
+///
+/// A record is a `>header` line followed by one or more sequence lines; a
+/// sequence that is wrapped across multiple lines is concatenated before
+/// being packed. A sequence containing IUPAC ambiguity codes (e.g. `N`)
+/// packs into `Record::Ambiguous` instead of failing outright.
+pub fn parse_fasta(input: &str) -> Result<Vec<(String, Record)>, ParseError> {
+    let (remainder, records) =
+        fasta_records(input).map_err(|e| ParseError::Malformed(e.to_string()))?;
+    if !remainder.is_empty() {
+        return Err(ParseError::Malformed(remainder.to_string()));
+    }
+    pack_records(records)
+}
+
+/// Parses FASTA-formatted bytes into `(header, Record)` records.
+///
+/// This is a variant of [`parse_fasta`] for callers that read their input as
+/// raw bytes (e.g. from a file). The whole input must be in `input`: it is
+/// decoded as UTF-8 and parsed in one pass, not incrementally.
+pub fn parse_fasta_bytes(input: &[u8]) -> Result<Vec<(String, Record)>, ParseError> {
+    let text = std::str::from_utf8(input)
+        .map_err(|e| ParseError::Malformed(e.to_string()))?;
+    parse_fasta(text)
+}
+
+/// Parses a FASTQ-formatted string into `(id, Record)` records.
+///
+/// A record is four lines: `@id`, sequence, `+`, and a quality string. The
+/// quality string is currently discarded; only the sequence is packed. A
+/// sequence containing IUPAC ambiguity codes (e.g. `N`) packs into
+/// `Record::Ambiguous` instead of failing outright.
+pub fn parse_fastq(input: &str) -> Result<Vec<(String, Record)>, ParseError> {
+    let (remainder, records) =
+        fastq_records(input).map_err(|e| ParseError::Malformed(e.to_string()))?;
+    if !remainder.is_empty() {
+        return Err(ParseError::Malformed(remainder.to_string()));
+    }
+    pack_records(records)
+}
+
+/// Parses FASTQ-formatted bytes into `(id, Record)` records.
+///
+/// This is a variant of [`parse_fastq`] for callers that read their input as
+/// raw bytes (e.g. from a file). The whole input must be in `input`: it is
+/// decoded as UTF-8 and parsed in one pass, not incrementally.
+pub fn parse_fastq_bytes(input: &[u8]) -> Result<Vec<(String, Record)>, ParseError> {
+    let text = std::str::from_utf8(input)
+        .map_err(|e| ParseError::Malformed(e.to_string()))?;
+    parse_fastq(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests parsing a single-line FASTA record.
+    #[test]
+    fn parse_fasta_single_line() {
+        let input = ">seq1\nACGT\n";
+        let records = parse_fasta(input).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].0, "seq1");
+        assert_eq!(records[0].1, Record::Dna(PackedDna::from_str("ACGT").unwrap()));
+    }
+
+    /// Tests that a sequence wrapped across multiple lines is concatenated.
+    #[test]
+    fn parse_fasta_wrapped_sequence() {
+        let input = ">seq1\nACGT\nACGT\n>seq2\nTTTT\n";
+        let records = parse_fasta(input).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].0, "seq1");
+        assert_eq!(
+            records[0].1,
+            Record::Dna(PackedDna::from_str("ACGTACGT").unwrap())
+        );
+        assert_eq!(records[1].0, "seq2");
+        assert_eq!(records[1].1, Record::Dna(PackedDna::from_str("TTTT").unwrap()));
+    }
+
+    /// Tests that malformed FASTA input is rejected.
+    #[test]
+    fn parse_fasta_malformed() {
+        assert!(parse_fasta("seq1\nACGT\n").is_err());
+    }
+
+    /// Tests that trailing content that isn't a valid record is rejected
+    /// rather than silently dropped.
+    #[test]
+    fn parse_fasta_rejects_trailing_garbage() {
+        assert!(parse_fasta(">seq1\nACGT\ngarbage not fasta @#$\n").is_err());
+    }
+
+    /// Tests that a truncated trailing record (header with no sequence
+    /// line) is rejected rather than silently dropped.
+    #[test]
+    fn parse_fasta_rejects_truncated_trailing_record() {
+        assert!(parse_fasta(">seq1\nACGT\n>bad_header_no_seq_line").is_err());
+    }
+
+    /// Tests that a FASTA sequence containing an IUPAC ambiguity code (e.g.
+    /// `N`) packs as `Record::Ambiguous` instead of failing outright.
+    #[test]
+    fn parse_fasta_ambiguity_code_falls_back() {
+        let input = ">seq1\nACGTN\n";
+        let records = parse_fasta(input).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            records[0].1,
+            Record::Ambiguous(crate::packed::PackedAmbiguousDna::from_str("ACGTN").unwrap())
+        );
+    }
+
+    /// Tests parsing a single FASTQ record.
+    #[test]
+    fn parse_fastq_single_record() {
+        let input = "@read1\nACGT\n+\nIIII\n";
+        let records = parse_fastq(input).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].0, "read1");
+        assert_eq!(records[0].1, Record::Dna(PackedDna::from_str("ACGT").unwrap()));
+    }
+
+    /// Tests parsing multiple FASTQ records.
+    #[test]
+    fn parse_fastq_multiple_records() {
+        let input = "@read1\nACGT\n+\nIIII\n@read2\nTTAA\n+\nJJJJ\n";
+        let records = parse_fastq(input).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].0, "read2");
+        assert_eq!(records[1].1, Record::Dna(PackedDna::from_str("TTAA").unwrap()));
+    }
+}
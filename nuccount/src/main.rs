@@ -1,10 +1,3 @@
-// TODO: implement a nucleotide counter
-//
-// command line argument parsing has been provided
-// you must use the PackedDna struct you previously implemented
-// if there is any functionality you would like to add to PackedDna feel free to do so in the DNA
-// crate
-//
 // If run with `nuccount --dna ACGTTT" it should print the following to stdout:
 // ```
 // Input: ACGTTT
@@ -17,8 +10,10 @@
 //
 // be sure to exit with informative error messages if the input is invalid
 
+use dna::packed::{PackedAmbiguousDna, PackedDna};
+use dna::parser::{self, Record};
+use std::path::PathBuf;
 use structopt::StructOpt;
-use dna::packed::PackedDna;
 
 /// Count the number of occurrences of each nucleotide in the provided DNA.
 #[derive(Debug, StructOpt)]
@@ -26,38 +21,140 @@ struct Opts {
     /// The DNA sequence for which we should retrieve a nucleotide count.
     ///
     /// It is case insensitive but only nucleotides A, C, G and T are supported.
-    #[structopt(short = "d", long, required = true)]
-    dna: String,
-}
+    /// Mutually exclusive with `--file`.
+    #[structopt(short = "d", long, conflicts_with = "file")]
+    dna: Option<String>,
 
-fn main() {
-    let opts = Opts::from_args();
-    let dna = opts.dna;
-
-    // Convert the DNA sequence to PackedDna
-    let packed_dna = match <PackedDna as std::str::FromStr>::from_str(&dna) {
-        Ok(packed_dna) => packed_dna,
-        Err(error) => {
-            //Error: failed to parse nucleotide from X - failed char
-            eprintln!("Error: {}", error);
-            std::process::exit(1);
-        }
-    };
+    /// A FASTA or FASTQ file to read records from.
+    ///
+    /// Per-record nucleotide counts are printed for every record in the
+    /// file. Mutually exclusive with `--dna`.
+    #[structopt(short = "f", long, parse(from_os_str))]
+    file: Option<PathBuf>,
 
-    println!("Input: {}", &dna);
+    /// If set, print the top canonical k-mer counts of this length instead
+    /// of individual nucleotide counts. File records that contain IUPAC
+    /// ambiguity codes still get per-code counts, with a warning.
+    #[structopt(long)]
+    kmer: Option<usize>,
+}
 
-    // Count the nucleotides
+/// Prints the per-nucleotide counts for a single sequence, in the
+/// `A: n` / `C: n` / `G: n` / `T: n` format.
+fn print_nuc_counts(dna: &PackedDna) {
     let mut nucleotide_counts = [0; 4];
-    for nucleotide in packed_dna.0 {
+    for nucleotide in dna.iter() {
         nucleotide_counts[nucleotide as usize] += 1;
     }
 
-    // Print the nucleotide counts
     let nucleotides = ['A', 'C', 'G', 'T'];
     for (nucleotide, count) in nucleotides.iter().zip(nucleotide_counts.iter()) {
         println!("{}: {}", nucleotide, count);
     }
 }
 
+/// Prints the per-code counts for a sequence containing IUPAC ambiguity
+/// codes, in the same `LETTER: n` format as `print_nuc_counts`, skipping
+/// codes that don't appear.
+fn print_ambiguous_counts(dna: &PackedAmbiguousDna) {
+    let letters = [
+        'A', 'C', 'G', 'T', 'R', 'Y', 'S', 'W', 'K', 'M', 'B', 'D', 'H', 'V', 'N',
+    ];
+    let mut counts = [0; 15];
+    for code in dna.iter() {
+        counts[code as usize] += 1;
+    }
+
+    for (letter, count) in letters.iter().zip(counts.iter()) {
+        if *count > 0 {
+            println!("{}: {}", letter, count);
+        }
+    }
+}
+
+/// Prints the 10 most frequent canonical k-mers of a sequence, each as its
+/// `k`-character base string followed by its count.
+fn print_kmer_counts(dna: &PackedDna, k: usize) {
+    let mut counts: Vec<(u64, usize)> = dna.count_kmers(k).into_iter().collect();
+    counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let bases = ['A', 'C', 'G', 'T'];
+    for (kmer, count) in counts.into_iter().take(10) {
+        let kmer_str: String = (0..k)
+            .rev()
+            .map(|i| bases[((kmer >> (2 * i)) & 0b11) as usize])
+            .collect();
+        println!("{}: {}", kmer_str, count);
+    }
+}
+
+fn main() {
+    let opts = Opts::from_args();
+
+    if let Some(k) = opts.kmer {
+        if k == 0 || k > 32 {
+            eprintln!("Error: --kmer must be between 1 and 32, got {}", k);
+            std::process::exit(1);
+        }
+    }
+
+    if let Some(dna) = opts.dna {
+        let packed_dna = match <PackedDna as std::str::FromStr>::from_str(&dna) {
+            Ok(packed_dna) => packed_dna,
+            Err(error) => {
+                //Error: failed to parse nucleotide from X - failed char
+                eprintln!("Error: {}", error);
+                std::process::exit(1);
+            }
+        };
+
+        println!("Input: {}", &dna);
+        println!();
+
+        match opts.kmer {
+            Some(k) => print_kmer_counts(&packed_dna, k),
+            None => print_nuc_counts(&packed_dna),
+        }
+    } else if let Some(path) = opts.file {
+        let contents = match std::fs::read(&path) {
+            Ok(contents) => contents,
+            Err(error) => {
+                eprintln!("Error: failed to read {}: {}", path.display(), error);
+                std::process::exit(1);
+            }
+        };
 
+        let records = match contents.first() {
+            Some(b'@') => parser::parse_fastq_bytes(&contents),
+            _ => parser::parse_fasta_bytes(&contents),
+        };
+        let records = match records {
+            Ok(records) => records,
+            Err(error) => {
+                eprintln!("Error: {}", error);
+                std::process::exit(1);
+            }
+        };
 
+        for (name, record) in &records {
+            println!("{}:", name);
+            match (record, opts.kmer) {
+                (Record::Dna(dna), Some(k)) => print_kmer_counts(dna, k),
+                (Record::Dna(dna), None) => print_nuc_counts(dna),
+                (Record::Ambiguous(dna), Some(_)) => {
+                    eprintln!(
+                        "Warning: {} contains IUPAC ambiguity codes; --kmer is not \
+                         supported for it, falling back to per-code counts",
+                        name
+                    );
+                    print_ambiguous_counts(dna);
+                }
+                (Record::Ambiguous(dna), None) => print_ambiguous_counts(dna),
+            }
+            println!();
+        }
+    } else {
+        eprintln!("Error: one of --dna or --file is required");
+        std::process::exit(1);
+    }
+}